@@ -0,0 +1,142 @@
+/*
+ * Copyright 2018 Google Inc. All rights reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::ops::{Deref, Range};
+use std::sync::Arc;
+
+use crate::buffer::Buffer;
+
+/// An owning, reference-counted `Buffer` backed by `Arc<[u8]>`.
+///
+/// Unlike `&[u8]`, an `ArcBuffer` doesn't borrow from anything: it can be stored in a struct,
+/// sent across threads, and cached alongside (or instead of) the bytes it was parsed from.
+/// Because `slice` must return `Self` and a bare `Arc<[u8]>` has no way to narrow its span
+/// without losing the shared pointer, `ArcBuffer` pairs the `Arc` with a `Range<usize>` that
+/// marks the live window into it.
+#[derive(Clone, Debug)]
+pub struct ArcBuffer {
+    data: Arc<[u8]>,
+    range: Range<usize>,
+}
+
+impl ArcBuffer {
+    /// Wraps the entirety of `data` as an `ArcBuffer`.
+    #[inline]
+    pub fn new(data: Arc<[u8]>) -> Self {
+        let range = 0..data.len();
+        ArcBuffer { data, range }
+    }
+}
+
+impl Deref for ArcBuffer {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.data[self.range.clone()]
+    }
+}
+
+impl PartialEq for ArcBuffer {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl Buffer for ArcBuffer {
+    type BufferString = crate::buffer::ArcBufferString;
+
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        let start = self.range.start + range.start;
+        let end = self.range.start + range.end;
+        Some(ArcBuffer {
+            data: self.data.clone(),
+            range: start..end,
+        })
+    }
+
+    #[inline]
+    fn shallow_copy(&self) -> Self {
+        // Clones the `Arc` (bumping the refcount) without touching the range.
+        ArcBuffer {
+            data: self.data.clone(),
+            range: self.range.clone(),
+        }
+    }
+
+    #[inline]
+    fn from_static_slice(slice: &'static [u8]) -> Self {
+        ArcBuffer::new(Arc::from(slice))
+    }
+
+    #[inline]
+    fn buffer_str(&self) -> Result<Self::BufferString, std::str::Utf8Error> {
+        std::str::from_utf8(self)?;
+        Ok(crate::buffer::ArcBufferString(self.shallow_copy()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_narrows_the_live_window_and_shares_storage() {
+        let buf = ArcBuffer::new(Arc::from(&b"hello world"[..]));
+
+        let narrowed = buf.slice(6..11).unwrap();
+        assert_eq!(&*narrowed, b"world");
+
+        // Slicing again is relative to the narrowed window, not the original buffer.
+        let narrower = narrowed.slice(1..4).unwrap();
+        assert_eq!(&*narrower, b"orl");
+    }
+
+    #[test]
+    fn slice_out_of_bounds_returns_none() {
+        let buf = ArcBuffer::new(Arc::from(&b"hello"[..]));
+
+        assert!(buf.slice(0..6).is_none());
+        assert!(buf.slice(3..1).is_none());
+    }
+
+    #[test]
+    fn shallow_copy_shares_the_same_backing_storage() {
+        let buf = ArcBuffer::new(Arc::from(&b"hello"[..]));
+        let copy = buf.shallow_copy();
+
+        assert_eq!(Arc::strong_count(&buf.data), 2);
+        assert_eq!(buf, copy);
+    }
+
+    #[test]
+    fn buffer_str_round_trips_valid_utf8() {
+        let buf = ArcBuffer::new(Arc::from(&b"hi"[..]));
+        let s = buf.buffer_str().expect("valid utf8");
+        assert_eq!(&*s, "hi");
+    }
+
+    #[test]
+    fn buffer_str_rejects_invalid_utf8() {
+        let buf = ArcBuffer::new(Arc::from(&[0xff, 0xfe][..]));
+        assert!(buf.buffer_str().is_err());
+    }
+}
@@ -88,3 +88,134 @@ impl<'de> Buffer for &'de [u8] {
     }
 }
 
+/// A `BufferString` for [`bytes::Bytes`]-backed buffers.
+///
+/// Wraps a `Bytes` range that has already been validated as UTF-8, so `Deref<Target = str>`
+/// can be implemented without re-checking on every access.
+#[cfg(feature = "bytes")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BytesString(bytes::Bytes);
+
+#[cfg(feature = "bytes")]
+impl Deref for BytesString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        // Safety: `BytesString` is only ever constructed from bytes that have already
+        // passed `std::str::from_utf8`, in `<bytes::Bytes as Buffer>::buffer_str`.
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+/// Lets `Table`/`Vector` accessors own a refcounted, zero-copy view of the buffer that can
+/// outlive the original `&[u8]` the data was read from.
+#[cfg(feature = "bytes")]
+impl Buffer for bytes::Bytes {
+    type BufferString = BytesString;
+
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        Some(self.slice(range))
+    }
+
+    #[inline]
+    fn shallow_copy(&self) -> Self {
+        // `Bytes::clone` just bumps the refcount, it doesn't copy the underlying storage.
+        self.clone()
+    }
+
+    #[inline]
+    fn from_static_slice(slice: &'static [u8]) -> Self {
+        bytes::Bytes::from_static(slice)
+    }
+
+    #[inline]
+    fn buffer_str(&self) -> Result<Self::BufferString, std::str::Utf8Error> {
+        std::str::from_utf8(self)?;
+        Ok(BytesString(self.clone()))
+    }
+}
+
+#[cfg(all(test, feature = "bytes"))]
+mod bytes_tests {
+    use super::*;
+
+    #[test]
+    fn slice_narrows_the_live_window() {
+        let buf = bytes::Bytes::from_static(b"hello world");
+
+        let narrowed = Buffer::slice(&buf, 6..11).unwrap();
+        assert_eq!(&narrowed[..], b"world");
+    }
+
+    #[test]
+    fn slice_out_of_bounds_returns_none() {
+        let buf = bytes::Bytes::from_static(b"hello");
+
+        assert!(Buffer::slice(&buf, 0..6).is_none());
+        assert!(Buffer::slice(&buf, 3..1).is_none());
+    }
+
+    #[test]
+    fn shallow_copy_is_a_cheap_clone_not_a_deep_copy() {
+        let buf = bytes::Bytes::from_static(b"hello");
+        let copy = buf.shallow_copy();
+
+        assert_eq!(buf, copy);
+        assert_eq!(buf.as_ptr(), copy.as_ptr());
+    }
+
+    #[test]
+    fn buffer_str_round_trips_valid_utf8() {
+        let buf = bytes::Bytes::from_static(b"hi");
+        let s = buf.buffer_str().expect("valid utf8");
+        assert_eq!(&*s, "hi");
+    }
+
+    #[test]
+    fn buffer_str_rejects_invalid_utf8() {
+        let buf = bytes::Bytes::from_static(&[0xff, 0xfe]);
+        assert!(buf.buffer_str().is_err());
+    }
+}
+
+/// A `BufferString` for `ArcBuffer`-backed buffers.
+///
+/// Wraps an `ArcBuffer` range that has already been validated as UTF-8, so `Deref<Target = str>`
+/// can be implemented without re-checking on every access.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcBufferString(pub(crate) crate::arc_buffer::ArcBuffer);
+
+impl Deref for ArcBufferString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        // Safety: `ArcBufferString` is only ever constructed from bytes that have already
+        // passed `std::str::from_utf8`, in `<ArcBuffer as Buffer>::buffer_str`.
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+/// A `BufferString` for `VecBuffer`-backed buffers.
+///
+/// Wraps a `VecBuffer` that has already been validated as UTF-8, so `Deref<Target = str>` can
+/// be implemented without re-checking on every access.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VecBufferString(pub(crate) crate::mut_buffer::VecBuffer);
+
+impl Deref for VecBufferString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        // Safety: `VecBufferString` is only ever constructed from bytes that have already
+        // passed `std::str::from_utf8`, in `<VecBuffer as Buffer>::buffer_str`.
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
@@ -0,0 +1,287 @@
+/*
+ * Copyright 2018 Google Inc. All rights reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::marker::PhantomData;
+
+use crate::buffer::Buffer;
+use crate::endian_scalar::read_scalar_at;
+use crate::follow::Follow;
+use crate::primitives::*;
+use crate::table::Table;
+use crate::vector::Vector;
+use crate::verifier::{InvalidFlatbuffer, Verifiable, Verifier, VerifierOptions};
+use crate::vtable::VTable;
+
+/// A `Table` accessor that validates lazily: instead of `root_with_opts`'s full recursive pass
+/// up front, every `get` validates only the bytes that access actually dereferences (the
+/// vtable, and the field it points at). This is the middle ground between `root_with_opts`
+/// (verify everything, pay up front) and `root_unchecked` (verify nothing, trust the caller):
+/// pay-as-you-go safety for random-access workloads over untrusted input.
+///
+/// `opts` is reused on every access, so the depth/apparent-size limits that bound a full
+/// `Verifier` pass also bound the work any single access can do.
+pub struct CheckedTable<'opts, B> {
+    buf: B,
+    loc: usize,
+    opts: &'opts VerifierOptions,
+}
+
+impl<'opts, B> CheckedTable<'opts, B>
+where
+    B: Buffer,
+{
+    #[inline]
+    pub fn new(opts: &'opts VerifierOptions, buf: B, loc: usize) -> Self {
+        CheckedTable { buf, loc, opts }
+    }
+
+    /// Validates the vtable's own bytes (its size, the table's size, and the slot range they
+    /// imply) before reading it, then validates and follows the field at `slot_byte_loc`.
+    /// Returns `default` if the vtable has no entry for the slot, exactly like `Table::get`.
+    pub fn get<T>(
+        &self,
+        slot_byte_loc: VOffsetT,
+        default: Option<T::Inner>,
+    ) -> Result<Option<T::Inner>, InvalidFlatbuffer>
+    where
+        T: Follow<B> + Verifiable,
+    {
+        let mut v = Verifier::new(self.opts, &self.buf);
+        <BackwardsSOffset<VTable<B>>>::run_verifier(&mut v, self.loc)?;
+
+        let table = Table::new(self.buf.shallow_copy(), self.loc);
+        let o = table.vtable().get(slot_byte_loc) as usize;
+        if o == 0 {
+            return Ok(default);
+        }
+
+        let field_loc = self.loc + o;
+        T::run_verifier(&mut v, field_loc)?;
+        Ok(Some(T::follow(self.buf.shallow_copy(), field_loc)))
+    }
+
+    /// Wraps an already-fetched vector field so that its elements are validated lazily too,
+    /// just like this table's own fields are.
+    ///
+    /// `vector` may have been built directly via the public `Vector::new` at an arbitrary,
+    /// unverified location (not necessarily one that came from a validated `get::<Vector<_>>`
+    /// call above), so the bytes of the vector's own length prefix are bounds-checked here, up
+    /// front, rather than read blindly the way an unchecked `Vector::len` does. The declared
+    /// length is not checked against the buffer's size; each element is checked when read.
+    #[inline]
+    pub fn checked_vector<T>(
+        &self,
+        vector: Vector<B, T>,
+    ) -> Result<CheckedVector<'opts, B, T>, InvalidFlatbuffer> {
+        CheckedVector::new(self.opts, vector)
+    }
+}
+
+/// A `Vector` accessor that validates lazily, mirroring `CheckedTable`: `get`/iteration check
+/// only the element being dereferenced, instead of the whole vector being verified up front.
+pub struct CheckedVector<'opts, B, T> {
+    buf: B,
+    loc: usize,
+    len: usize,
+    opts: &'opts VerifierOptions,
+    phantom: PhantomData<T>,
+}
+
+impl<'opts, B, T> CheckedVector<'opts, B, T>
+where
+    B: Buffer,
+{
+    /// Bounds-checks the bytes of `vector`'s length prefix before reading it, then caches the
+    /// declared length. The declared length itself is not checked against the buffer's size:
+    /// every other access this type offers checks the single element it dereferences.
+    #[inline]
+    fn new(
+        opts: &'opts VerifierOptions,
+        vector: Vector<B, T>,
+    ) -> Result<Self, InvalidFlatbuffer> {
+        let buf = vector.buf();
+        let loc = vector.loc();
+        let mut v = Verifier::new(opts, &buf);
+        <UOffsetT>::run_verifier(&mut v, loc)?;
+        let len = read_scalar_at::<UOffsetT>(&buf, loc) as usize;
+        Ok(CheckedVector {
+            buf,
+            loc,
+            len,
+            opts,
+            phantom: PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'opts, B, T> CheckedVector<'opts, B, T>
+where
+    B: Buffer,
+    T: Follow<B> + Verifiable,
+{
+    /// Validates the element at `idx` before reading it, instead of trusting the buffer.
+    ///
+    /// Returns `None` if `idx` is outside this vector's own declared length (read from the
+    /// bounds-checked length prefix), mirroring `<[T]>::get`: an out-of-range index is not malformed-buffer data, it's
+    /// simply absent, so it's reported the same way a missing table slot is rather than as an
+    /// `InvalidFlatbuffer` error.
+    pub fn get(&self, idx: usize) -> Option<Result<T::Inner, InvalidFlatbuffer>> {
+        if idx >= self.len {
+            return None;
+        }
+        let mut v = Verifier::new(self.opts, &self.buf);
+        let elem_loc = self.loc + SIZE_UOFFSET + core::mem::size_of::<T>() * idx;
+        Some(
+            T::run_verifier(&mut v, elem_loc)
+                .map(|_| T::follow(self.buf.shallow_copy(), elem_loc)),
+        )
+    }
+
+    #[inline]
+    pub fn iter(&self) -> CheckedVectorIter<'opts, '_, B, T> {
+        CheckedVectorIter {
+            vector: self,
+            idx: 0,
+        }
+    }
+}
+
+/// An iterator over a `CheckedVector`, validating each element as it is yielded.
+pub struct CheckedVectorIter<'opts, 'v, B, T> {
+    vector: &'v CheckedVector<'opts, B, T>,
+    idx: usize,
+}
+
+impl<'opts, 'v, B, T> Iterator for CheckedVectorIter<'opts, 'v, B, T>
+where
+    B: Buffer,
+    T: Follow<B> + Verifiable,
+{
+    type Item = Result<T::Inner, InvalidFlatbuffer>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.vector.get(self.idx)?;
+        self.idx += 1;
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endian_scalar::emplace_scalar;
+    use crate::get_root::checked_root;
+    use crate::verifier::VerifierOptions;
+
+    // A minimal flatbuffer: a root offset pointing at a table with one `u32` field at vtable
+    // slot 4. Layout: [root offset][vtable (padded to 8 bytes)][table: soffset + field].
+    fn flatbuffer_bytes(field_value: u32) -> Vec<u8> {
+        let mut buf = vec![0u8; 4 + 8 + 8];
+        emplace_scalar(&mut buf[0..4], 12u32); // root offset -> table at byte 12
+        emplace_scalar(&mut buf[4..6], 6u16); // vtable size
+        emplace_scalar(&mut buf[6..8], 8u16); // table (object) size
+        emplace_scalar(&mut buf[8..10], 4u16); // field offset within the table
+        emplace_scalar(&mut buf[12..16], 8i32); // soffset from the table back to the vtable
+        emplace_scalar(&mut buf[16..20], field_value);
+        buf
+    }
+
+    #[test]
+    fn checked_root_resolves_the_root_offset_before_reading_fields() {
+        let opts = VerifierOptions::default();
+        let buf = flatbuffer_bytes(55);
+
+        let table = checked_root(&opts, &buf[..]).expect("valid root offset");
+        let value = table
+            .get::<u32>(4, None)
+            .expect("field bytes are in bounds");
+
+        assert_eq!(value, Some(55));
+    }
+
+    #[test]
+    fn checked_root_missing_slot_returns_default() {
+        let opts = VerifierOptions::default();
+        let buf = flatbuffer_bytes(55);
+
+        let table = checked_root(&opts, &buf[..]).expect("valid root offset");
+        let value = table.get::<u32>(6, None).expect("slot is absent, not OOB");
+
+        assert_eq!(value, None);
+    }
+
+    // A standalone `Vector<u32>`: a 4-byte length prefix followed by the elements.
+    fn vector_bytes(values: &[u32]) -> Vec<u8> {
+        let mut buf = vec![0u8; 4 + values.len() * 4];
+        emplace_scalar(&mut buf[0..4], values.len() as u32);
+        for (i, value) in values.iter().enumerate() {
+            let start = 4 + i * 4;
+            emplace_scalar(&mut buf[start..start + 4], *value);
+        }
+        buf
+    }
+
+    #[test]
+    fn checked_vector_get_is_in_bounds_then_out_of_bounds() {
+        let opts = VerifierOptions::default();
+        let buf = vector_bytes(&[10, 20, 30]);
+        let table = CheckedTable::new(&opts, &buf[..], 0);
+        let vector: Vector<&[u8], u32> = Vector::new(&buf[..], 0);
+        let checked = table
+            .checked_vector(vector)
+            .expect("length prefix is in bounds");
+
+        assert_eq!(checked.len(), 3);
+        assert_eq!(checked.get(1), Some(Ok(20)));
+        assert!(checked.get(3).is_none());
+    }
+
+    #[test]
+    fn checked_vector_iter_yields_every_validated_element() {
+        let opts = VerifierOptions::default();
+        let buf = vector_bytes(&[1, 2, 3]);
+        let table = CheckedTable::new(&opts, &buf[..], 0);
+        let vector: Vector<&[u8], u32> = Vector::new(&buf[..], 0);
+        let checked = table
+            .checked_vector(vector)
+            .expect("length prefix is in bounds");
+
+        let collected: Result<Vec<u32>, _> = checked.iter().collect();
+        assert_eq!(collected.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn checked_vector_rejects_a_length_prefix_outside_the_buffer() {
+        let opts = VerifierOptions::default();
+        let buf = vector_bytes(&[10, 20, 30]);
+        let table = CheckedTable::new(&opts, &buf[..], 0);
+        // Built directly via the public `Vector::new` at a location nothing has verified.
+        let vector: Vector<&[u8], u32> = Vector::new(&buf[..], buf.len() + 100);
+
+        assert!(table.checked_vector(vector).is_err());
+    }
+}
@@ -14,9 +14,11 @@
  * limitations under the License.
  */
 
+use crate::endian_scalar::read_scalar_at;
+use crate::primitives::UOffsetT;
 use crate::{
-    Buffer, Follow, ForwardsUOffset, InvalidFlatbuffer, SkipSizePrefix, Verifiable, Verifier,
-    VerifierOptions,
+    Buffer, CheckedTable, Follow, ForwardsUOffset, InvalidFlatbuffer, SkipSizePrefix, Verifiable,
+    Verifier, VerifierOptions,
 };
 
 /// Gets the root of the Flatbuffer, verifying it first with default options.
@@ -97,6 +99,31 @@ where
     <ForwardsUOffset<T>>::follow(data, 0)
 }
 
+#[inline]
+/// Gets the root of the Flatbuffer as a `CheckedTable`, skipping the upfront recursive
+/// verification pass. Every subsequent access validates just the bytes it dereferences,
+/// reusing `opts`'s depth/apparent-size limits to bound the work any single access can do.
+/// This is the middle ground between `root_with_opts` (verify everything, pay up front) and
+/// `root_unchecked` (verify nothing, trust the caller): pay-as-you-go safety for random-access
+/// workloads over untrusted input.
+///
+/// Byte 0 of a Flatbuffer is a forward `UOffsetT` *to* the root table, not the table itself, so
+/// this still has to verify and follow that one offset up front (mirroring what
+/// `ForwardsUOffset<T>` does inside `root_with_opts`) before a `CheckedTable` can be built at
+/// the root's actual location.
+pub fn checked_root<'opts, B>(
+    opts: &'opts VerifierOptions,
+    data: B,
+) -> Result<CheckedTable<'opts, B>, InvalidFlatbuffer>
+where
+    B: Buffer,
+{
+    let mut v = Verifier::new(&opts, &data);
+    <UOffsetT>::run_verifier(&mut v, 0)?;
+    let root_loc = read_scalar_at::<UOffsetT>(&data, 0) as usize;
+    Ok(CheckedTable::new(opts, data, root_loc))
+}
+
 #[inline]
 /// Gets root for a trusted, size prefixed, Flatbuffer.
 /// # Safety
@@ -0,0 +1,210 @@
+/*
+ * Copyright 2018 Google Inc. All rights reserved.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::ops::{Deref, Range};
+use std::rc::Rc;
+
+use crate::buffer::Buffer;
+
+/// A `Buffer` that additionally allows writing back into the bytes it was built from.
+///
+/// Mirrors the immutable/mutable split used by other buffer libraries (e.g. `bytes::Buf` vs.
+/// `bytes::BufMut`): `MutBuffer` layers in-place writes on top of everything `Buffer` already
+/// gives you for reads. This is what lets `Table::mutate`/`Vector::mutate` patch a scalar field
+/// in an existing buffer without rebuilding it.
+///
+/// Note there is no `impl MutBuffer for &mut [u8]`: `Buffer` requires `Clone`, and cloning a
+/// `&mut [u8]` would alias a unique reference. `VecBuffer` below is the owned buffer that plays
+/// that role instead.
+pub trait MutBuffer: Buffer {
+    /// Returns a mutable view of `range`, or `None` if it is out of bounds, or if this handle
+    /// does not currently have unique ownership of the underlying storage (see `VecBuffer`'s
+    /// doc comment for why mutation requires uniqueness).
+    fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [u8]>;
+}
+
+/// An owning buffer backed by `Vec<u8>`, for use with `MutBuffer`-based in-place mutation.
+///
+/// Every `Buffer` accessor (`Table::get`, and critically `VectorIter::next` on every single
+/// iteration step) calls `shallow_copy()` internally, so it has to be a cheap refcount bump —
+/// the same way `ArcBuffer`/`bytes::Bytes` are — rather than a deep copy of the backing bytes.
+/// `VecBuffer` therefore stores its data behind a plain `Rc<Vec<u8>>` plus a `Range<usize>`
+/// marking its live window, mirroring `ArcBuffer`'s `Arc<[u8]>` + `Range<usize>` split.
+///
+/// Deliberately *not* `Rc<RefCell<Vec<u8>>>`: a `RefCell`'s dynamic borrow tracking only holds
+/// for the lifetime of a `Ref`/`RefMut` guard, and `Deref::deref`'s signature has nowhere to
+/// stash one — any attempt to read through the `RefCell` without holding a guard for as long as
+/// the returned reference lives is unsound, not just "caller must be careful" unsafe. Using a
+/// bare `Rc` sidesteps that entirely: reads (`Deref`) only ever need shared access, which `Rc`
+/// already hands out safely, and mutation goes through `Rc::get_mut`, which only succeeds when
+/// this handle is the *only* outstanding reference (i.e. no `shallow_copy` of this buffer is
+/// still alive). That is the trade this type makes: mutation requires unique ownership instead
+/// of being shared across clones, exactly like `Table::mutate` returning `false` for an absent
+/// slot, a shared `VecBuffer` now simply can't be mutated (`get_mut` returns `None`) rather than
+/// racing another clone's reads.
+#[derive(Clone, Debug)]
+pub struct VecBuffer {
+    data: Rc<Vec<u8>>,
+    range: Range<usize>,
+}
+
+impl VecBuffer {
+    /// Wraps `data` as a `VecBuffer`.
+    #[inline]
+    pub fn new(data: Vec<u8>) -> Self {
+        let range = 0..data.len();
+        VecBuffer {
+            data: Rc::new(data),
+            range,
+        }
+    }
+}
+
+impl Deref for VecBuffer {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.data[self.range.clone()]
+    }
+}
+
+impl PartialEq for VecBuffer {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl Buffer for VecBuffer {
+    type BufferString = crate::buffer::VecBufferString;
+
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        let start = self.range.start + range.start;
+        let end = self.range.start + range.end;
+        Some(VecBuffer {
+            data: self.data.clone(),
+            range: start..end,
+        })
+    }
+
+    #[inline]
+    fn shallow_copy(&self) -> Self {
+        // Clones the `Rc` (bumping the refcount) without touching the range.
+        VecBuffer {
+            data: self.data.clone(),
+            range: self.range.clone(),
+        }
+    }
+
+    #[inline]
+    fn from_static_slice(slice: &'static [u8]) -> Self {
+        VecBuffer::new(slice.to_vec())
+    }
+
+    #[inline]
+    fn buffer_str(&self) -> Result<Self::BufferString, std::str::Utf8Error> {
+        std::str::from_utf8(self)?;
+        Ok(crate::buffer::VecBufferString(self.shallow_copy()))
+    }
+}
+
+impl MutBuffer for VecBuffer {
+    fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [u8]> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        let start = self.range.start + range.start;
+        let end = self.range.start + range.end;
+        // `Rc::get_mut` only returns `Some` when `self.data` is the sole remaining reference
+        // (strong count 1, no weak refs), i.e. no other `shallow_copy` of this buffer is alive.
+        // That's a real, safe, compiler-enforced check rather than an unsafe bypass, at the cost
+        // of mutation failing on a still-shared `VecBuffer` instead of racing its other clones.
+        let vec = Rc::get_mut(&mut self.data)?;
+        vec.get_mut(start..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_narrows_the_live_window_and_shares_storage() {
+        let buf = VecBuffer::new(b"hello world".to_vec());
+
+        let narrowed = buf.slice(6..11).unwrap();
+        assert_eq!(&*narrowed, b"world");
+
+        // Slicing again is relative to the narrowed window, not the original buffer.
+        let narrower = narrowed.slice(1..4).unwrap();
+        assert_eq!(&*narrower, b"orl");
+    }
+
+    #[test]
+    fn slice_out_of_bounds_returns_none() {
+        let buf = VecBuffer::new(b"hello".to_vec());
+
+        assert!(buf.slice(0..6).is_none());
+        assert!(buf.slice(3..1).is_none());
+    }
+
+    #[test]
+    fn get_mut_overwrites_bytes_in_place_when_uniquely_owned() {
+        let mut buf = VecBuffer::new(b"hello".to_vec());
+
+        let bytes = buf.get_mut(0..2).expect("uniquely owned, in bounds");
+        bytes.copy_from_slice(b"HE");
+
+        assert_eq!(&*buf, b"HEllo");
+    }
+
+    #[test]
+    fn get_mut_out_of_bounds_returns_none() {
+        let mut buf = VecBuffer::new(b"hello".to_vec());
+
+        assert!(buf.get_mut(4..6).is_none());
+        assert!(buf.get_mut(3..1).is_none());
+    }
+
+    #[test]
+    fn get_mut_on_a_shared_buffer_returns_none_instead_of_mutating() {
+        let mut buf = VecBuffer::new(b"hello".to_vec());
+        let _clone = buf.shallow_copy();
+
+        // `_clone` keeps the `Rc` strong count above 1, so this handle is no longer uniquely
+        // owned and must refuse to mutate rather than racing the other clone's reads.
+        assert!(buf.get_mut(0..2).is_none());
+        assert_eq!(&*buf, b"hello");
+    }
+
+    #[test]
+    fn buffer_str_round_trips_valid_utf8() {
+        let buf = VecBuffer::new(b"hi".to_vec());
+        let s = buf.buffer_str().expect("valid utf8");
+        assert_eq!(&*s, "hi");
+    }
+
+    #[test]
+    fn buffer_str_rejects_invalid_utf8() {
+        let buf = VecBuffer::new(vec![0xff, 0xfe]);
+        assert!(buf.buffer_str().is_err());
+    }
+}
@@ -15,7 +15,9 @@
  */
 
 use crate::buffer::Buffer;
+use crate::endian_scalar::{emplace_scalar, EndianScalar};
 use crate::follow::Follow;
+use crate::mut_buffer::MutBuffer;
 use crate::primitives::*;
 use crate::vtable::VTable;
 
@@ -48,6 +50,27 @@ impl<B> Table<B> where B: Buffer {
     }
 }
 
+impl<B> Table<B> where B: MutBuffer {
+    /// Mutates the scalar field at `slot` in place, writing `value`'s endian-encoded bytes
+    /// directly into the buffer. Returns `false` if the vtable has no entry for `slot`: exactly
+    /// like `get`, mutation looks up the field offset via the vtable, and a field that was
+    /// defaulted away at build time cannot be conjured into existence by mutating it.
+    pub fn mutate<T: EndianScalar>(&mut self, slot_byte_loc: VOffsetT, value: T) -> bool {
+        let o = self.vtable().get(slot_byte_loc) as usize;
+        if o == 0 {
+            return false;
+        }
+        let start = self.loc + o;
+        match self.buf.get_mut(start..start + core::mem::size_of::<T>()) {
+            Some(bytes) => {
+                emplace_scalar(bytes, value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 impl<B> Follow<B> for Table<B> where B: Buffer {
     type Inner = Table<B>;
     #[inline]
@@ -68,3 +91,40 @@ pub fn buffer_has_identifier(data: &[u8], ident: &str, size_prefixed: bool) -> b
 
     ident.as_bytes() == got
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mut_buffer::VecBuffer;
+
+    // A table with one `u32` field at vtable slot 4: a 6-byte vtable (size 6, object size 8,
+    // one field offset of 4) padded to 8 bytes, followed by the table itself (a 4-byte soffset
+    // back to the vtable, then the field's 4 bytes).
+    fn table_bytes(field_value: u32) -> Vec<u8> {
+        let mut buf = vec![0u8; 8 + 8];
+        emplace_scalar(&mut buf[0..2], 6u16); // vtable size
+        emplace_scalar(&mut buf[2..4], 8u16); // table (object) size
+        emplace_scalar(&mut buf[4..6], 4u16); // field offset within the table
+        emplace_scalar(&mut buf[8..12], 8i32); // soffset from the table back to the vtable
+        emplace_scalar(&mut buf[12..16], field_value);
+        buf
+    }
+
+    #[test]
+    fn mutate_missing_slot_returns_false() {
+        let buf = VecBuffer::new(table_bytes(42));
+        let mut table: Table<VecBuffer> = Table::new(buf, 8);
+
+        assert!(!table.mutate::<u32>(6, 99));
+        assert_eq!(table.get::<u32>(4, None), Some(42));
+    }
+
+    #[test]
+    fn mutate_present_slot_overwrites_field() {
+        let buf = VecBuffer::new(table_bytes(42));
+        let mut table: Table<VecBuffer> = Table::new(buf, 8);
+
+        assert!(table.mutate::<u32>(4, 99));
+        assert_eq!(table.get::<u32>(4, None), Some(99));
+    }
+}
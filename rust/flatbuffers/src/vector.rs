@@ -17,14 +17,14 @@
 use std::fmt::{Debug, Formatter, Result};
 use std::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator};
 use std::marker::PhantomData;
-use std::mem::size_of;
+use std::mem::{align_of, size_of};
 use std::slice::from_raw_parts;
 
 use crate::buffer::Buffer;
-use crate::endian_scalar::read_scalar_at;
-#[cfg(target_endian = "little")]
+use crate::endian_scalar::{emplace_scalar, read_scalar_at};
 use crate::endian_scalar::EndianScalar;
 use crate::follow::Follow;
+use crate::mut_buffer::MutBuffer;
 use crate::primitives::*;
 
 pub struct Vector<B, T>(B, usize, PhantomData<T>);
@@ -77,6 +77,20 @@ impl<B, T> Vector<B, T> where B: Buffer {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// The location of this vector's length prefix. Exposed crate-internally so that
+    /// `CheckedVector` can re-derive a `Vector`'s position without reaching into its private
+    /// fields.
+    #[inline(always)]
+    pub(crate) fn loc(&self) -> usize {
+        self.1
+    }
+
+    /// A shallow copy of this vector's underlying buffer. See the `loc` doc comment.
+    #[inline(always)]
+    pub(crate) fn buf(&self) -> B {
+        self.0.shallow_copy()
+    }
 }
 
 impl<B: Buffer, T: Follow<B>> Vector<B, T> {
@@ -94,50 +108,78 @@ impl<B: Buffer, T: Follow<B>> Vector<B, T> {
     }
 }
 
-pub trait SafeSliceAccess {}
-impl<'a, B, T: SafeSliceAccess> Vector<B, T> where B: Buffer {
-    pub fn safe_slice(self) -> &'a [T] {
-        let buf = self.0;
-        let loc = self.1;
+impl<B: Buffer, T: EndianScalar> Vector<B, T> {
+    /// Iterates over the vector's elements, decoding each one with `read_scalar_at` (which
+    /// already byte-swaps via `EndianScalar`). This is the `Buffer`-aware, endian-correct
+    /// replacement for the old `safe_slice`: it works on every platform and every `Buffer`,
+    /// not just little-endian targets backed by `&[u8]`.
+    #[inline]
+    pub fn copied(&self) -> VectorIter<B, T> {
+        self.iter()
+    }
+
+    /// Returns the vector's elements as a borrowed `&[T]`, but only when doing so is actually
+    /// sound: the underlying buffer bytes must be aligned to `align_of::<T>()` and the host
+    /// must be little-endian (matching the on-wire representation). Returns `None` otherwise,
+    /// in which case callers should fall back to `copied()`.
+    pub fn try_slice(&self) -> Option<&[T]> {
+        if !cfg!(target_endian = "little") {
+            return None;
+        }
         let sz = size_of::<T>();
         debug_assert!(sz > 0);
-        let len = read_scalar_at::<UOffsetT>(&buf, loc) as usize;
-        let data_buf = &buf[loc + SIZE_UOFFSET..loc + SIZE_UOFFSET + len * sz];
-        let ptr = data_buf.as_ptr() as *const T;
-        // FIXME(colindjk) Buffer<T> this is very unsafe. Move this logic to Buffer impl.
-        let s: &'a [T] = unsafe { from_raw_parts(ptr, len) };
-        s
+        let len = self.len();
+        let start = self.1 + SIZE_UOFFSET;
+        let data_buf = &self.0[start..start + len * sz];
+        let ptr = data_buf.as_ptr();
+        if (ptr as usize) % align_of::<T>() != 0 {
+            return None;
+        }
+        // Safety: `ptr` is aligned to `align_of::<T>()` (checked above), points at
+        // `len * size_of::<T>()` bytes borrowed from `self.0` for the lifetime of `&self`, and
+        // the host is little-endian (checked above) so the in-buffer bytes already match `T`'s
+        // native representation.
+        Some(unsafe { from_raw_parts(ptr as *const T, len) })
     }
 }
 
-impl SafeSliceAccess for u8 {}
-impl SafeSliceAccess for i8 {}
-impl SafeSliceAccess for bool {}
-
-// TODO(caspern): Get rid of this. Conditional compliation is unnecessary complexity.
-// Vectors of primitives just don't work on big endian machines!!!
-#[cfg(target_endian = "little")]
-mod le_safe_slice_impls {
-    impl super::SafeSliceAccess for u16 {}
-    impl super::SafeSliceAccess for u32 {}
-    impl super::SafeSliceAccess for u64 {}
-
-    impl super::SafeSliceAccess for i16 {}
-    impl super::SafeSliceAccess for i32 {}
-    impl super::SafeSliceAccess for i64 {}
-
-    impl super::SafeSliceAccess for f32 {}
-    impl super::SafeSliceAccess for f64 {}
+impl<B: MutBuffer, T: EndianScalar> Vector<B, T> {
+    /// Mutates the element at `idx` in place, writing `value`'s endian-encoded bytes directly
+    /// into the buffer. Returns `false` if `idx` is outside this vector's own declared length:
+    /// exactly like `Table::mutate` returning `false` for an absent slot, an out-of-range index
+    /// here must not silently overwrite whatever bytes happen to live past the end of this
+    /// vector (other fields, vtables, other vectors), so the check has to hold in release
+    /// builds too, not just as a `debug_assert!`.
+    pub fn mutate(&mut self, idx: usize, value: T) -> bool {
+        if idx >= self.len() {
+            return false;
+        }
+        let sz = size_of::<T>();
+        let start = self.1 + SIZE_UOFFSET + sz * idx;
+        match self.0.get_mut(start..start + sz) {
+            Some(bytes) => {
+                emplace_scalar(bytes, value);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
-#[cfg(target_endian = "little")]
-pub use self::le_safe_slice_impls::*;
-
-pub fn follow_cast_ref<'a, T: Sized + 'a>(buf: &'a [u8], loc: usize) -> &'a T {
+/// Casts the `size_of::<T>()` bytes at `loc` to `&T`, but only when `loc` is aligned to
+/// `align_of::<T>()`. Returns `None` otherwise, instead of the unaligned-pointer UB hazard the
+/// old unconditional cast had; callers that need a value regardless of alignment should read it
+/// through `read_scalar_at` (or field-by-field) instead.
+pub fn follow_cast_ref<'a, T: Sized + 'a>(buf: &'a [u8], loc: usize) -> Option<&'a T> {
     let sz = size_of::<T>();
     let buf = &buf[loc..loc + sz];
-    let ptr = buf.as_ptr() as *const T;
-    unsafe { &*ptr }
+    let ptr = buf.as_ptr();
+    if (ptr as usize) % align_of::<T>() != 0 {
+        return None;
+    }
+    // Safety: `ptr` is aligned to `align_of::<T>()` (checked above) and points at `sz` live
+    // bytes borrowed from `buf` for the lifetime of `'a`.
+    Some(unsafe { &*(ptr as *const T) })
 }
 
 impl<'a, B> Follow<B> for &'a str where B: 'a + Buffer {
@@ -152,28 +194,6 @@ impl<'a, B> Follow<B> for &'a str where B: 'a + Buffer {
     }
 }
 
-#[cfg(target_endian = "little")]
-fn follow_slice_helper<'a, B: Buffer + 'a, T>(buf: B, loc: usize) -> &'a [T] {
-    let sz = size_of::<T>();
-    debug_assert!(sz > 0);
-    let len = read_scalar_at::<UOffsetT>(&buf, loc) as usize;
-    let data_buf = &buf[loc + SIZE_UOFFSET..loc + SIZE_UOFFSET + len * sz];
-    let ptr = data_buf.as_ptr() as *const T;
-    // FIXME(colindjk) Buffer<T> This is very unsafe :(
-    // Need to double check if this is _actually_ unsafe, b/c of the guarantee from Buffer.
-    let s: &[T] = unsafe { from_raw_parts(ptr, len) };
-    s
-}
-
-/// Implement direct slice access if the host is little-endian.
-#[cfg(target_endian = "little")]
-impl<'a, B: Buffer + 'a, T: EndianScalar> Follow<B> for &'a [T] {
-    type Inner = &'a [T];
-    fn follow(buf: B, loc: usize) -> Self::Inner {
-        follow_slice_helper::<B, T>(buf, loc)
-    }
-}
-
 /// Implement Follow for all possible Vectors that have Follow-able elements.
 impl<B: Buffer, T: Follow<B>> Follow<B> for Vector<B, T> {
     type Inner = Vector<B, T>;
@@ -302,3 +322,55 @@ impl<'a, B: Buffer, T: Follow<B>> IntoIterator for &'a Vector<B, T> {
         self.iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mut_buffer::VecBuffer;
+
+    // A length-3 `Vector<u32>`: a 4-byte length prefix followed by 3 little-endian u32s.
+    fn vector_bytes() -> Vec<u8> {
+        let mut buf = vec![0u8; 4 + 3 * 4];
+        emplace_scalar(&mut buf[0..4], 3u32);
+        emplace_scalar(&mut buf[4..8], 10u32);
+        emplace_scalar(&mut buf[8..12], 20u32);
+        emplace_scalar(&mut buf[12..16], 30u32);
+        buf
+    }
+
+    #[test]
+    fn try_slice_matches_copied_when_aligned() {
+        let buf = vector_bytes();
+        let vector: Vector<&[u8], u32> = Vector::new(&buf[..], 0);
+
+        let copied: Vec<u32> = vector.copied().collect();
+        assert_eq!(copied, vec![10, 20, 30]);
+
+        // `Vec<u8>`'s backing allocation is word-aligned, so on a little-endian host the
+        // 4-byte-aligned u32 data here should be sliceable directly.
+        if cfg!(target_endian = "little") {
+            assert_eq!(vector.try_slice(), Some(&[10u32, 20, 30][..]));
+        }
+    }
+
+    #[test]
+    fn vector_mutate_overwrites_element_in_place() {
+        let buf = VecBuffer::new(vector_bytes());
+        let mut vector: Vector<VecBuffer, u32> = Vector::new(buf, 0);
+
+        assert!(vector.mutate(1, 99));
+
+        assert_eq!(vector.get(1), 99);
+        assert_eq!(vector.iter().collect::<Vec<u32>>(), vec![10, 99, 30]);
+    }
+
+    #[test]
+    fn vector_mutate_out_of_bounds_returns_false_and_leaves_buffer_untouched() {
+        let buf = VecBuffer::new(vector_bytes());
+        let mut vector: Vector<VecBuffer, u32> = Vector::new(buf, 0);
+
+        assert!(!vector.mutate(3, 99));
+
+        assert_eq!(vector.iter().collect::<Vec<u32>>(), vec![10, 20, 30]);
+    }
+}